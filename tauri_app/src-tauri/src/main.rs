@@ -1,8 +1,16 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use serde::Serialize;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
-use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, State, WebviewUrl, WebviewWindowBuilder};
 
 #[derive(Serialize, Clone)]
 struct QueueItem {
@@ -10,6 +18,31 @@ struct QueueItem {
     name: String,
     path: String,
     kind: String,
+    size: u64,
+    modified: u64,
+}
+
+const VIDEO_EXTENSIONS: &[&str] = &["mov", "mp4", "avi", "mkv", "webm", "m4v"];
+const PHOTO_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "webp", "bmp"];
+
+#[derive(Serialize, Clone)]
+struct ProgressEvent {
+    id: String,
+    /// `None` when the source duration couldn't be probed (indeterminate progress);
+    /// the final `progress=end` event always reports `Some(100.0)`.
+    percent: Option<f64>,
+    fps: f64,
+    /// Wall-clock seconds remaining, derived from ffmpeg's reported encode
+    /// `speed`; `None` when duration or speed aren't known yet. The final
+    /// `progress=end` event always reports `Some(0.0)`.
+    eta: Option<f64>,
+}
+
+#[derive(Default)]
+struct ConversionState {
+    pending: Mutex<VecDeque<QueueItem>>,
+    active: Mutex<Option<(String, Child)>>,
+    running: Mutex<bool>,
 }
 
 type Result<T> = std::result::Result<T, String>;
@@ -18,57 +51,105 @@ fn path_to_string(path: PathBuf) -> String {
     path.to_string_lossy().to_string()
 }
 
+fn extension_lower(name: &str) -> String {
+    PathBuf::from(name)
+        .extension()
+        .and_then(|v| v.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+}
+
 fn kind_from_name(name: &str) -> String {
-    let lower = name.to_lowercase();
-    if lower.ends_with(".jpg")
-        || lower.ends_with(".jpeg")
-        || lower.ends_with(".png")
-        || lower.ends_with(".webp")
-        || lower.ends_with(".bmp")
-    {
+    if PHOTO_EXTENSIONS.contains(&extension_lower(name).as_str()) {
         "photo".to_string()
     } else {
         "video".to_string()
     }
 }
 
+fn is_media_file(name: &str) -> bool {
+    let extension = extension_lower(name);
+    PHOTO_EXTENSIONS.contains(&extension.as_str()) || VIDEO_EXTENSIONS.contains(&extension.as_str())
+}
+
+fn queue_item_from_path(path: &std::path::Path) -> Option<QueueItem> {
+    let name = path.file_name().and_then(|v| v.to_str())?.to_string();
+    let metadata = fs::metadata(path).ok()?;
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+
+    Some(QueueItem {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: name.clone(),
+        path: path_to_string(path.to_path_buf()),
+        kind: kind_from_name(&name),
+        size: metadata.len(),
+        modified,
+    })
+}
+
+fn scan_media_files(dir: &std::path::Path, recursive: bool, visited: &mut HashSet<PathBuf>) -> Vec<QueueItem> {
+    let canonical = fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf());
+    if !visited.insert(canonical) {
+        return Vec::new();
+    }
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            log::error!("failed to read directory {}: {err}", dir.display());
+            return Vec::new();
+        }
+    };
+
+    let mut items = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let file_type = match entry.file_type() {
+            Ok(file_type) => file_type,
+            Err(_) => continue,
+        };
+
+        if file_type.is_dir() {
+            if recursive {
+                items.extend(scan_media_files(&path, recursive, visited));
+            }
+            continue;
+        }
+
+        let name = path.file_name().and_then(|v| v.to_str()).unwrap_or("");
+        if is_media_file(name) {
+            if let Some(item) = queue_item_from_path(&path) {
+                items.push(item);
+            }
+        }
+    }
+    items
+}
+
 #[tauri::command]
 fn pick_files() -> Result<Vec<QueueItem>> {
     let selections = rfd::FileDialog::new().pick_files().unwrap_or_default();
-    let mut items = Vec::new();
-    for path in selections {
-        let name = path
-            .file_name()
-            .and_then(|v| v.to_str())
-            .unwrap_or("file")
-            .to_string();
-        items.push(QueueItem {
-            id: uuid::Uuid::new_v4().to_string(),
-            name: name.clone(),
-            path: path_to_string(path),
-            kind: kind_from_name(&name),
-        });
-    }
+    let items = selections
+        .iter()
+        .filter_map(|path| queue_item_from_path(path))
+        .collect();
     Ok(items)
 }
 
 #[tauri::command]
-fn pick_folder() -> Result<Vec<QueueItem>> {
-    let path = rfd::FileDialog::new()
-        .pick_folder()
-        .map(path_to_string)
-        .unwrap_or_default();
-
-    if path.is_empty() {
+fn pick_folder(include_subfolders: Option<bool>) -> Result<Vec<QueueItem>> {
+    let path = rfd::FileDialog::new().pick_folder();
+    let Some(path) = path else {
         return Ok(Vec::new());
-    }
+    };
 
-    Ok(vec![QueueItem {
-        id: uuid::Uuid::new_v4().to_string(),
-        name: "folder_item.jpg".to_string(),
-        path,
-        kind: "photo".to_string(),
-    }])
+    let mut visited = HashSet::new();
+    Ok(scan_media_files(&path, include_subfolders.unwrap_or(true), &mut visited))
 }
 
 #[tauri::command]
@@ -80,6 +161,31 @@ fn pick_output() -> Result<String> {
     Ok(path)
 }
 
+fn percent_encode_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+fn run_to_completion(mut command: Command) -> Result<()> {
+    let program = command.get_program().to_string_lossy().to_string();
+    let status = command
+        .status()
+        .map_err(|err| format!("failed to launch {program}: {err}"))?;
+
+    if !status.success() {
+        return Err(format!("{program} exited with status {status}"));
+    }
+    Ok(())
+}
+
 #[tauri::command]
 fn open_output(path: String) -> Result<()> {
     if path.is_empty() {
@@ -87,19 +193,103 @@ fn open_output(path: String) -> Result<()> {
     }
 
     #[cfg(target_os = "macos")]
-    {
-        let _ = std::process::Command::new("open").arg(path).status();
+    let command = {
+        let mut command = Command::new("open");
+        command.arg(path);
+        command
+    };
+    #[cfg(target_os = "windows")]
+    let command = {
+        let mut command = Command::new("explorer");
+        command.arg(path);
+        command
+    };
+    #[cfg(target_os = "linux")]
+    let command = {
+        let mut command = Command::new("xdg-open");
+        command.arg(path);
+        command
+    };
+
+    run_to_completion(command)
+}
+
+#[tauri::command]
+fn reveal_in_folder(path: String) -> Result<()> {
+    if path.is_empty() {
+        return Err("no path provided".to_string());
     }
+
     #[cfg(target_os = "windows")]
     {
-        let _ = std::process::Command::new("explorer").arg(path).status();
+        let mut command = Command::new("explorer");
+        command.arg(format!("/select,{path}"));
+        return run_to_completion(command);
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let mut command = Command::new("open");
+        command.args(["-R", &path]);
+        return run_to_completion(command);
     }
+
     #[cfg(target_os = "linux")]
     {
-        let _ = std::process::Command::new("xdg-open").arg(path).status();
+        let uri = format!("file://{}", percent_encode_path(&path));
+        let mut show_items = Command::new("dbus-send");
+        show_items.args([
+            "--session",
+            "--dest=org.freedesktop.FileManager1",
+            "--type=method_call",
+            "/org/freedesktop/FileManager1",
+            "org.freedesktop.FileManager1.ShowItems",
+            &format!("array:string:{uri}"),
+            "string:",
+        ]);
+        if run_to_completion(show_items).is_ok() {
+            return Ok(());
+        }
+
+        let parent = PathBuf::from(&path)
+            .parent()
+            .map(path_to_string)
+            .unwrap_or(path);
+        let mut fallback = Command::new("xdg-open");
+        fallback.arg(parent);
+        return run_to_completion(fallback);
     }
 
-    Ok(())
+    #[allow(unreachable_code)]
+    Err("unsupported platform".to_string())
+}
+
+#[tauri::command]
+fn open_with_default(path: String) -> Result<()> {
+    if path.is_empty() {
+        return Err("no path provided".to_string());
+    }
+
+    #[cfg(target_os = "macos")]
+    let command = {
+        let mut command = Command::new("open");
+        command.arg(path);
+        command
+    };
+    #[cfg(target_os = "windows")]
+    let command = {
+        let mut command = Command::new("cmd");
+        command.arg("/C").arg("start").arg("\"\"").arg(path);
+        command
+    };
+    #[cfg(target_os = "linux")]
+    let command = {
+        let mut command = Command::new("xdg-open");
+        command.arg(path);
+        command
+    };
+
+    run_to_completion(command)
 }
 
 #[tauri::command]
@@ -132,32 +322,436 @@ fn pick_ffmpeg() -> Result<String> {
     Ok(path)
 }
 
+#[derive(Serialize, Clone, Default)]
+struct FfmpegProbe {
+    found: bool,
+    version: Option<String>,
+    has_libx264: bool,
+    has_aac: bool,
+    has_nvenc: bool,
+}
+
+const LINUX_DEFAULT_PATH_DIRS: &[&str] = &[
+    "/usr/local/sbin",
+    "/usr/local/bin",
+    "/usr/sbin",
+    "/usr/bin",
+    "/sbin",
+    "/bin",
+];
+
+fn normalized_linux_path() -> String {
+    let mut seen = HashSet::new();
+    let mut entries = Vec::new();
+
+    if let Ok(existing) = std::env::var("PATH") {
+        for entry in existing.split(':') {
+            if !entry.is_empty() && seen.insert(entry.to_string()) {
+                entries.push(entry.to_string());
+            }
+        }
+    }
+    for dir in LINUX_DEFAULT_PATH_DIRS {
+        if seen.insert(dir.to_string()) {
+            entries.push(dir.to_string());
+        }
+    }
+
+    entries.join(":")
+}
+
+fn ffmpeg_probe_command(program: &str) -> Command {
+    let mut command = Command::new(program);
+    #[cfg(target_os = "linux")]
+    {
+        command.env("PATH", normalized_linux_path());
+    }
+    command
+}
+
 #[tauri::command]
-fn check_ffmpeg() -> Result<bool> {
-    Ok(true)
+fn check_ffmpeg(ffmpeg_path: String) -> Result<FfmpegProbe> {
+    let program = if ffmpeg_path.trim().is_empty() {
+        "ffmpeg".to_string()
+    } else {
+        ffmpeg_path
+    };
+
+    let version_output = match ffmpeg_probe_command(&program).arg("-version").output() {
+        Ok(output) if output.status.success() => output,
+        Ok(_) => return Ok(FfmpegProbe::default()),
+        Err(err) => {
+            log::error!("ffmpeg binary not found at {program}: {err}");
+            return Ok(FfmpegProbe::default());
+        }
+    };
+
+    let version = String::from_utf8_lossy(&version_output.stdout)
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string());
+
+    let encoders_text = ffmpeg_probe_command(&program)
+        .arg("-encoders")
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).to_string())
+        .unwrap_or_default();
+
+    Ok(FfmpegProbe {
+        found: true,
+        version,
+        has_libx264: encoders_text.contains("libx264"),
+        has_aac: encoders_text.contains("aac"),
+        has_nvenc: encoders_text.contains("nvenc"),
+    })
+}
+
+fn file_modified_secs(path: &str) -> u64 {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+fn thumbnail_cache_path(
+    app: &AppHandle,
+    path: &str,
+    modified: u64,
+    max_width: u32,
+    max_height: u32,
+) -> Option<PathBuf> {
+    let dir = app.path().app_cache_dir().ok()?.join("thumbnails");
+    fs::create_dir_all(&dir).ok()?;
+
+    let mut hasher = DefaultHasher::new();
+    path.hash(&mut hasher);
+    let digest = hasher.finish();
+
+    Some(dir.join(format!("{digest:x}_{modified}_{max_width}x{max_height}.png")))
+}
+
+fn resize_to_png(image: image::DynamicImage, max_width: u32, max_height: u32) -> Result<Vec<u8>> {
+    let resized = image.resize(max_width, max_height, image::imageops::FilterType::Lanczos3);
+    let mut bytes = Vec::new();
+    resized
+        .write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageFormat::Png)
+        .map_err(|err| format!("failed to encode thumbnail: {err}"))?;
+    Ok(bytes)
+}
+
+fn photo_thumbnail_png(path: &str, max_width: u32, max_height: u32) -> Result<Vec<u8>> {
+    let image =
+        image::open(path).map_err(|err| format!("failed to decode image {path}: {err}"))?;
+    resize_to_png(image, max_width, max_height)
+}
+
+fn video_poster_png(ffmpeg_path: &str, path: &str, max_width: u32, max_height: u32) -> Result<Vec<u8>> {
+    let output = Command::new(ffmpeg_path)
+        .args([
+            "-ss",
+            "00:00:01",
+            "-i",
+            path,
+            "-vframes",
+            "1",
+            "-f",
+            "image2pipe",
+            "-vcodec",
+            "png",
+            "pipe:1",
+        ])
+        .stderr(Stdio::null())
+        .output()
+        .map_err(|err| {
+            log::error!("failed to extract poster frame for {path}: {err}");
+            format!("failed to extract a poster frame for {path}: {err}")
+        })?;
+
+    if !output.status.success() {
+        return Err(format!("ffmpeg could not extract a poster frame for {path}"));
+    }
+
+    let image = image::load_from_memory(&output.stdout)
+        .map_err(|err| format!("failed to decode poster frame for {path}: {err}"))?;
+    resize_to_png(image, max_width, max_height)
+}
+
+#[tauri::command]
+fn generate_thumbnail(
+    app: AppHandle,
+    path: String,
+    max_width: u32,
+    max_height: u32,
+    ffmpeg_path: String,
+) -> Result<String> {
+    let modified = file_modified_secs(&path);
+    let cache_path = thumbnail_cache_path(&app, &path, modified, max_width, max_height);
+
+    if let Some(cache_path) = &cache_path {
+        if let Ok(cached) = fs::read(cache_path) {
+            return Ok(format!("data:image/png;base64,{}", STANDARD.encode(cached)));
+        }
+    }
+
+    let name = PathBuf::from(&path)
+        .file_name()
+        .and_then(|v| v.to_str())
+        .unwrap_or("")
+        .to_string();
+    let png_bytes = if kind_from_name(&name) == "photo" {
+        photo_thumbnail_png(&path, max_width, max_height)?
+    } else {
+        video_poster_png(&ffmpeg_path, &path, max_width, max_height)?
+    };
+
+    if let Some(cache_path) = &cache_path {
+        if let Err(err) = fs::write(cache_path, &png_bytes) {
+            log::error!("failed to cache thumbnail for {path}: {err}");
+        }
+    }
+
+    Ok(format!("data:image/png;base64,{}", STANDARD.encode(png_bytes)))
+}
+
+fn ffprobe_path_from_ffmpeg(ffmpeg_path: &str) -> String {
+    let file_name = if cfg!(target_os = "windows") {
+        "ffprobe.exe"
+    } else {
+        "ffprobe"
+    };
+
+    match PathBuf::from(ffmpeg_path).parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => path_to_string(dir.join(file_name)),
+        _ => file_name.to_string(),
+    }
+}
+
+fn parse_duration_from_stderr(stderr: &str) -> Option<f64> {
+    let line = stderr
+        .lines()
+        .find(|line| line.trim_start().starts_with("Duration:"))?;
+    let timestamp = line.trim_start().strip_prefix("Duration:")?.trim();
+    let timestamp = timestamp.split(',').next()?.trim();
+
+    let mut parts = timestamp.split(':');
+    let hours: f64 = parts.next()?.parse().ok()?;
+    let minutes: f64 = parts.next()?.parse().ok()?;
+    let seconds: f64 = parts.next()?.parse().ok()?;
+    Some(hours * 3600.0 + minutes * 60.0 + seconds)
+}
+
+fn probe_duration_seconds(ffmpeg_path: &str, input: &str) -> Option<f64> {
+    let ffprobe_path = ffprobe_path_from_ffmpeg(ffmpeg_path);
+    let probe = Command::new(&ffprobe_path)
+        .args([
+            "-v",
+            "quiet",
+            "-show_entries",
+            "format=duration",
+            "-of",
+            "csv=p=0",
+            input,
+        ])
+        .output();
+
+    if let Ok(probe) = probe {
+        if probe.status.success() {
+            if let Ok(seconds) = String::from_utf8_lossy(&probe.stdout).trim().parse::<f64>() {
+                return Some(seconds);
+            }
+        }
+    }
+
+    let probe = Command::new(ffmpeg_path).args(["-i", input]).output().ok()?;
+    parse_duration_from_stderr(&String::from_utf8_lossy(&probe.stderr))
+}
+
+/// Picks a destination under `output_dir` that doesn't already exist, so two
+/// inputs with the same basename (common with recursive folder scans, or an
+/// input `.mp4` that already lives in `output_dir`) never clobber each other.
+fn output_path_for(input: &str, output_dir: &str) -> PathBuf {
+    let stem = PathBuf::from(input)
+        .file_stem()
+        .and_then(|v| v.to_str())
+        .unwrap_or("output")
+        .to_string();
+
+    let mut candidate = PathBuf::from(output_dir).join(format!("{stem}.mp4"));
+    let mut suffix = 1;
+    while candidate.exists() {
+        candidate = PathBuf::from(output_dir).join(format!("{stem} ({suffix}).mp4"));
+        suffix += 1;
+    }
+    candidate
+}
+
+fn read_conversion_progress(
+    app: &AppHandle,
+    item_id: &str,
+    duration: f64,
+    stdout: std::process::ChildStdout,
+) {
+    let mut fps = 0.0;
+    let mut out_time_secs = 0.0;
+    let mut speed = None;
+
+    for line in BufReader::new(stdout).lines().flatten() {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim();
+
+        match key {
+            "fps" => fps = value.parse().unwrap_or(fps),
+            "speed" => speed = value.trim_end_matches('x').parse::<f64>().ok(),
+            "out_time_us" | "out_time_ms" => {
+                if let Ok(microseconds) = value.parse::<f64>() {
+                    out_time_secs = microseconds / 1_000_000.0;
+                }
+            }
+            "progress" => {
+                let is_end = value == "end";
+                let percent = if is_end {
+                    Some(100.0)
+                } else if duration > 0.0 {
+                    Some((out_time_secs / duration * 100.0).min(100.0))
+                } else {
+                    None
+                };
+                let eta = if is_end {
+                    Some(0.0)
+                } else {
+                    match speed {
+                        Some(speed) if speed > 0.0 && duration > 0.0 => {
+                            Some((duration - out_time_secs).max(0.0) / speed)
+                        }
+                        _ => None,
+                    }
+                };
+                let _ = app.emit(
+                    "conversion://progress",
+                    ProgressEvent {
+                        id: item_id.to_string(),
+                        percent,
+                        fps,
+                        eta,
+                    },
+                );
+                if is_end {
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Drains the pending queue one item at a time so a batch never runs more
+/// than one ffmpeg encode at once; `stop_conversion` can still discard
+/// whatever hasn't been popped yet.
+fn process_conversion_queue(app: AppHandle, ffmpeg_path: String, output_dir: String) {
+    loop {
+        let state = app.state::<ConversionState>();
+        let Some(item) = state.pending.lock().unwrap().pop_front() else {
+            break;
+        };
+
+        let duration = probe_duration_seconds(&ffmpeg_path, &item.path).unwrap_or(0.0);
+        let output_path = output_path_for(&item.path, &output_dir);
+
+        let spawned = Command::new(&ffmpeg_path)
+            .args(["-n", "-i", &item.path, "-c:v", "libx264", "-c:a", "aac"])
+            .arg(path_to_string(output_path))
+            .args(["-progress", "pipe:1", "-nostats"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn();
+
+        let mut child = match spawned {
+            Ok(child) => child,
+            Err(err) => {
+                log::error!("failed to spawn ffmpeg for {}: {err}", item.path);
+                continue;
+            }
+        };
+
+        let Some(stdout) = child.stdout.take() else {
+            log::error!("ffmpeg did not expose stdout for {}", item.path);
+            continue;
+        };
+        state.active.lock().unwrap().replace((item.id.clone(), child));
+
+        read_conversion_progress(&app, &item.id, duration, stdout);
+
+        if let Some((_, mut child)) = state.active.lock().unwrap().take() {
+            if let Err(err) = child.wait() {
+                log::error!("ffmpeg process for {} did not exit cleanly: {err}", item.id);
+            }
+        }
+    }
+
+    *app.state::<ConversionState>().running.lock().unwrap() = false;
 }
 
 #[tauri::command]
-fn start_conversion(ffmpeg_path: String, output_dir: String) -> Result<()> {
-    let _ = (ffmpeg_path, output_dir);
+fn start_conversion(
+    app: AppHandle,
+    state: State<ConversionState>,
+    queue: Vec<QueueItem>,
+    ffmpeg_path: String,
+    output_dir: String,
+) -> Result<()> {
+    state
+        .pending
+        .lock()
+        .unwrap()
+        .extend(queue.into_iter().filter(|item| item.kind == "video"));
+
+    let mut running = state.running.lock().unwrap();
+    if *running {
+        return Ok(());
+    }
+    *running = true;
+    drop(running);
+
+    let app_handle = app.clone();
+    std::thread::spawn(move || process_conversion_queue(app_handle, ffmpeg_path, output_dir));
+
     Ok(())
 }
 
 #[tauri::command]
-fn stop_conversion() -> Result<()> {
+fn stop_conversion(state: State<ConversionState>) -> Result<()> {
+    state.pending.lock().unwrap().clear();
+
+    if let Some((id, mut child)) = state.active.lock().unwrap().take() {
+        if let Err(err) = child.kill() {
+            log::error!("failed to kill ffmpeg process for {id}: {err}");
+        }
+        if let Err(err) = child.wait() {
+            log::error!("ffmpeg process for {id} did not exit cleanly: {err}");
+        }
+    }
+
     Ok(())
 }
 
 fn main() {
     tauri::Builder::default()
+        .manage(ConversionState::default())
         .invoke_handler(tauri::generate_handler![
             pick_files,
             pick_folder,
             pick_output,
             open_output,
+            reveal_in_folder,
+            open_with_default,
             open_settings_window,
             pick_ffmpeg,
             check_ffmpeg,
+            generate_thumbnail,
             start_conversion,
             stop_conversion
         ])